@@ -0,0 +1,23 @@
+use std::fmt::{self, Display};
+
+pub trait Backend {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BackendError>;
+    fn set(&self, key: &str, previous: Option<Vec<u8>>, value: Vec<u8>) -> Result<(), BackendError>;
+}
+
+#[derive(Debug)]
+pub enum BackendError {
+    Unavailable(String),
+    Conflict,
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Unavailable(msg) => write!(f, "backend unavailable: {msg}"),
+            BackendError::Conflict => write!(f, "backend value conflict"),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+pub type CodecError = Box<dyn Error + Send + Sync>;
+
+/// How a limiter instance's stored value is encoded on the wire. Swapping
+/// the codec lets operators inspect/debug values directly in a backend like
+/// Redis, or share counters with non-Rust consumers, without touching any
+/// algorithm's logic.
+pub trait Codec {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, CodecError>;
+    fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, CodecError>;
+}
+
+/// The crate's historical default: compact, but opaque to anything outside
+/// Rust.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeCodec;
+
+impl Codec for BincodeCodec {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, CodecError> {
+        bincode::serialize(value).map_err(Into::into)
+    }
+
+    fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, CodecError> {
+        bincode::deserialize(bytes).map_err(Into::into)
+    }
+}
+
+/// Human-readable alternative for operators who want to inspect stored
+/// values directly, or polyglot services that need to read the same
+/// counters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, CodecError> {
+        serde_json::to_vec(value).map_err(Into::into)
+    }
+
+    fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, CodecError> {
+        serde_json::from_slice(bytes).map_err(Into::into)
+    }
+}
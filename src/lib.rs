@@ -0,0 +1,8 @@
+pub mod backend;
+pub mod codec;
+pub mod registry;
+pub mod types;
+
+pub use codec::{BincodeCodec, Codec, JsonCodec};
+pub use registry::Registry;
+pub use types::{LimiterInstance, LimiterType, RateLimiterError};
@@ -0,0 +1,48 @@
+use crate::backend::{Backend, BackendError};
+use crate::codec::{BincodeCodec, Codec};
+use crate::types::{LimiterConfig, LimiterType, RateLimiterError};
+use std::collections::HashMap;
+
+/// A set of named limiters sharing one backend, each with its own algorithm
+/// and thresholds, keyed by an action category (e.g. `"login"`, `"upload"`).
+/// Backend keys are namespaced per category so two categories checking the
+/// same caller key can never collide.
+pub struct Registry<B, C: Codec = BincodeCodec> {
+    backend: B,
+    limiters: HashMap<String, LimiterConfig<C>>,
+}
+
+impl<B: Backend, C: Codec> Registry<B, C> {
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            limiters: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, category: impl Into<String>, limiter: LimiterConfig<C>) -> &mut Self {
+        self.limiters.insert(category.into(), limiter);
+        self
+    }
+
+    pub fn check(&self, category: &str, key: &str) -> Result<(), RateLimiterError> {
+        let limiter = self
+            .limiters
+            .get(category)
+            .ok_or_else(|| RateLimiterError::UnknownCategory(category.to_string()))?;
+
+        let namespaced_key = format!("{category}:{key}");
+        let previous = self
+            .backend
+            .get(&namespaced_key)
+            .map_err(RateLimiterError::BackendError)?;
+        let next = limiter.is_ratelimited(previous.clone())?;
+
+        self.backend
+            .set(&namespaced_key, previous, next)
+            .map_err(|e| match e {
+                BackendError::Conflict => RateLimiterError::BackendConflict,
+                other => RateLimiterError::BackendError(other),
+            })
+    }
+}
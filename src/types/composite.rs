@@ -0,0 +1,54 @@
+use super::token_bucket::{TokenBucket, TokenBucketInstance};
+use super::{LimiterInstance, RateLimiterError, SerializableInstance};
+use crate::codec::{BincodeCodec, Codec};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenType {
+    Ops,
+    Bytes,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Composite<C: Codec = BincodeCodec> {
+    pub ops: TokenBucket<C>,
+    pub bytes: TokenBucket<C>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct CompositeInstance {
+    pub ops: TokenBucketInstance,
+    pub bytes: TokenBucketInstance,
+}
+
+impl SerializableInstance for CompositeInstance {}
+
+impl<C: Codec> Composite<C> {
+    /// Checks out `ops` and `bytes` tokens from their respective buckets,
+    /// only persisting the result once both have confirmed they have
+    /// budget, so a request rejected on one dimension never partially
+    /// drains the other.
+    pub fn is_ratelimited(
+        &self,
+        value: Option<Vec<u8>>,
+        ops: u32,
+        bytes: u32,
+    ) -> Result<Vec<u8>, RateLimiterError> {
+        let existing = value.map(CompositeInstance::from_bytes::<C>).transpose()?;
+        let (ops_instance, bytes_instance) = match existing {
+            Some(instance) => (Some(instance.ops), Some(instance.bytes)),
+            None => (None, None),
+        };
+
+        let ops = self.ops.consume(ops_instance, ops)?;
+        let bytes = self.bytes.consume(bytes_instance, bytes)?;
+
+        CompositeInstance { ops, bytes }.to_bytes::<C>()
+    }
+
+    pub fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        Ok(LimiterInstance::CompositeInstance(
+            CompositeInstance::from_bytes::<C>(value)?,
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+use super::{now_ns, LimiterInstance, LimiterType, RateLimitStatus, RateLimiterError, SerializableInstance};
+use crate::codec::{BincodeCodec, Codec};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub struct FixedWindow<C: Codec = BincodeCodec> {
+    pub size: u32,
+    pub window: Duration,
+    _codec: PhantomData<C>,
+}
+
+impl<C: Codec> Clone for FixedWindow<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Codec> Copy for FixedWindow<C> {}
+
+impl<C: Codec> fmt::Debug for FixedWindow<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedWindow")
+            .field("size", &self.size)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct FixedWindowInstance {
+    pub count: u32,
+    pub window_start: u64,
+}
+
+impl SerializableInstance for FixedWindowInstance {}
+
+impl<C: Codec> FixedWindow<C> {
+    pub fn new(size: u32, window: Duration) -> Self {
+        Self {
+            size,
+            window,
+            _codec: PhantomData,
+        }
+    }
+
+    fn current(&self, instance: Option<FixedWindowInstance>, now: u64) -> FixedWindowInstance {
+        let window_ns = self.window.as_nanos().max(1) as u64;
+        let mut instance = instance.unwrap_or(FixedWindowInstance {
+            count: 0,
+            window_start: now,
+        });
+        if now.saturating_sub(instance.window_start) >= window_ns {
+            instance.window_start = now;
+            instance.count = 0;
+        }
+        instance
+    }
+}
+
+impl<C: Codec> LimiterType for FixedWindow<C> {
+    fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError> {
+        let instance = value.map(FixedWindowInstance::from_bytes::<C>).transpose()?;
+        let mut instance = self.current(instance, now_ns());
+
+        if instance.count >= self.size {
+            return Err(RateLimiterError::RateExceeded);
+        }
+        instance.count += 1;
+        instance.to_bytes::<C>()
+    }
+
+    fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        Ok(LimiterInstance::FixedWindowInstance(
+            FixedWindowInstance::from_bytes::<C>(value)?,
+        ))
+    }
+
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError> {
+        let instance = value.map(FixedWindowInstance::from_bytes::<C>).transpose()?;
+        let now = now_ns();
+        let instance = self.current(instance, now);
+
+        let remaining = self.size.saturating_sub(instance.count);
+        let window_ns = self.window.as_nanos().max(1) as u64;
+        let reset_after = Duration::from_nanos(window_ns.saturating_sub(now.saturating_sub(instance.window_start)));
+
+        Ok(RateLimitStatus {
+            limit: self.size,
+            remaining,
+            reset_after,
+            retry_after: (remaining == 0).then_some(reset_after),
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use super::{now_ns, LimiterInstance, LimiterType, RateLimitStatus, RateLimiterError, SerializableInstance};
+use crate::codec::{BincodeCodec, Codec};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub struct LeakyBucket<C: Codec = BincodeCodec> {
+    pub size: u32,
+    pub leak_rate: u32,
+    _codec: PhantomData<C>,
+}
+
+impl<C: Codec> Clone for LeakyBucket<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Codec> Copy for LeakyBucket<C> {}
+
+impl<C: Codec> fmt::Debug for LeakyBucket<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeakyBucket")
+            .field("size", &self.size)
+            .field("leak_rate", &self.leak_rate)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct LeakyBucketInstance {
+    pub level: u32,
+    pub last_leak: u64,
+}
+
+impl SerializableInstance for LeakyBucketInstance {}
+
+impl<C: Codec> LeakyBucket<C> {
+    pub fn new(size: u32, leak_rate: u32) -> Self {
+        Self {
+            size,
+            leak_rate,
+            _codec: PhantomData,
+        }
+    }
+
+    fn current(&self, instance: Option<LeakyBucketInstance>, now: u64) -> LeakyBucketInstance {
+        let mut instance = instance.unwrap_or(LeakyBucketInstance { level: 0, last_leak: now });
+
+        let elapsed_ns = now.saturating_sub(instance.last_leak);
+        let leaked = (elapsed_ns as u128 * self.leak_rate as u128) / 1_000_000_000;
+        if leaked > 0 {
+            instance.level = instance.level.saturating_sub(leaked as u32);
+            instance.last_leak = now;
+        }
+        instance
+    }
+}
+
+impl<C: Codec> LimiterType for LeakyBucket<C> {
+    fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError> {
+        let instance = value.map(LeakyBucketInstance::from_bytes::<C>).transpose()?;
+        let mut instance = self.current(instance, now_ns());
+
+        if instance.level >= self.size {
+            return Err(RateLimiterError::RateExceeded);
+        }
+        instance.level += 1;
+        instance.to_bytes::<C>()
+    }
+
+    fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        Ok(LimiterInstance::LeakyBucketInstance(
+            LeakyBucketInstance::from_bytes::<C>(value)?,
+        ))
+    }
+
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError> {
+        let instance = value.map(LeakyBucketInstance::from_bytes::<C>).transpose()?;
+        let instance = self.current(instance, now_ns());
+
+        let remaining = self.size.saturating_sub(instance.level);
+        let reset_after = Duration::from_secs_f64(1.0 / self.leak_rate.max(1) as f64);
+
+        Ok(RateLimitStatus {
+            limit: self.size,
+            remaining,
+            reset_after,
+            retry_after: (remaining == 0).then_some(reset_after),
+        })
+    }
+}
@@ -1,22 +1,72 @@
+pub mod composite;
 pub mod fixed_window;
 pub mod leaky_bucket;
 pub mod sliding_window;
 pub mod token_bucket;
 
 use crate::backend::BackendError;
-use fixed_window::FixedWindowInstance;
-use leaky_bucket::LeakyBucketInstance;
+use crate::codec::{BincodeCodec, Codec, CodecError};
+use composite::CompositeInstance;
+use fixed_window::{FixedWindow, FixedWindowInstance};
+use leaky_bucket::{LeakyBucket, LeakyBucketInstance};
 use serde::{Deserialize, Serialize};
-use sliding_window::SlidingWindowInstance;
+use sliding_window::{SlidingWindow, SlidingWindowInstance};
 use std::{
     error::Error,
     fmt::{self, Debug, Display},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use token_bucket::TokenBucketInstance;
+use token_bucket::{TokenBucket, TokenBucketInstance};
+
+pub(crate) fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock before unix epoch")
+        .as_nanos() as u64
+}
 
 pub trait LimiterType: Clone {
     fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError>;
     fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError>;
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError>;
+
+    /// Time to wait before retrying a request against `value`, or `None` if
+    /// one would succeed right now. This is the `retry_after` of
+    /// [`Self::status`]: for window limiters the time until the current window
+    /// rolls over, for buckets one token's refill (or leak) interval. It is not
+    /// an exact wait: time already elapsed towards the next token is not
+    /// subtracted, and a sliding window can still be limited once its current
+    /// window has rolled over.
+    fn retry_after(&self, value: Option<Vec<u8>>) -> Result<Option<Duration>, RateLimiterError> {
+        Ok(self.status(value)?.retry_after)
+    }
+
+    /// Sleeps for [`Self::retry_after`], instead of forcing the caller to
+    /// poll-and-retry on [`RateLimiterError::RateExceeded`]. Returns
+    /// immediately if a request would already succeed; after the sleep a
+    /// request is not guaranteed to succeed.
+    #[cfg(feature = "async")]
+    fn await_slot(
+        &self,
+        value: Option<Vec<u8>>,
+    ) -> impl std::future::Future<Output = Result<(), RateLimiterError>> {
+        async move {
+            if let Some(duration) = self.retry_after(value)? {
+                tokio::time::sleep(duration).await;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Point-in-time view of a limiter's budget, suitable for surfacing as
+/// `X-RateLimit-*`/`Retry-After` response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_after: Duration,
+    pub retry_after: Option<Duration>,
 }
 
 #[derive(Debug)]
@@ -25,6 +75,7 @@ pub enum LimiterInstance {
     SlidingWindowInstance(SlidingWindowInstance),
     TokenBucketInstance(TokenBucketInstance),
     LeakyBucketInstance(LeakyBucketInstance),
+    CompositeInstance(CompositeInstance),
 }
 
 impl LimiterInstance {
@@ -55,23 +106,93 @@ impl LimiterInstance {
             _ => Err(RateLimiterError::MalformedValue(None)),
         }
     }
+
+    pub fn as_composite_instance(self) -> Result<CompositeInstance, RateLimiterError> {
+        match self {
+            Self::CompositeInstance(i) => Ok(i),
+            _ => Err(RateLimiterError::MalformedValue(None)),
+        }
+    }
+}
+
+/// A limiter configuration, dispatched over one of the built-in algorithms.
+///
+/// Unlike [`LimiterType`], this is an owned value that is not generic over the
+/// algorithm (only over the codec), so it can be
+/// stored alongside other differently-algorithmed limiters (e.g. in a
+/// [`crate::registry::Registry`]) without each one needing its own type
+/// parameter.
+pub enum LimiterConfig<C: Codec = BincodeCodec> {
+    FixedWindow(FixedWindow<C>),
+    SlidingWindow(SlidingWindow<C>),
+    TokenBucket(TokenBucket<C>),
+    LeakyBucket(LeakyBucket<C>),
+}
+
+impl<C: Codec> Clone for LimiterConfig<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Codec> Copy for LimiterConfig<C> {}
+
+impl<C: Codec> Debug for LimiterConfig<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FixedWindow(l) => f.debug_tuple("FixedWindow").field(l).finish(),
+            Self::SlidingWindow(l) => f.debug_tuple("SlidingWindow").field(l).finish(),
+            Self::TokenBucket(l) => f.debug_tuple("TokenBucket").field(l).finish(),
+            Self::LeakyBucket(l) => f.debug_tuple("LeakyBucket").field(l).finish(),
+        }
+    }
+}
+
+impl<C: Codec> LimiterType for LimiterConfig<C> {
+    fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError> {
+        match self {
+            Self::FixedWindow(l) => l.is_ratelimited(value),
+            Self::SlidingWindow(l) => l.is_ratelimited(value),
+            Self::TokenBucket(l) => l.is_ratelimited(value),
+            Self::LeakyBucket(l) => l.is_ratelimited(value),
+        }
+    }
+
+    fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        match self {
+            Self::FixedWindow(l) => l.window_instance(value),
+            Self::SlidingWindow(l) => l.window_instance(value),
+            Self::TokenBucket(l) => l.window_instance(value),
+            Self::LeakyBucket(l) => l.window_instance(value),
+        }
+    }
+
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError> {
+        match self {
+            Self::FixedWindow(l) => l.status(value),
+            Self::SlidingWindow(l) => l.status(value),
+            Self::TokenBucket(l) => l.status(value),
+            Self::LeakyBucket(l) => l.status(value),
+        }
+    }
 }
 
 trait SerializableInstance: Debug + Serialize + for<'de> Deserialize<'de> {
-    fn from_bytes(bytes: Vec<u8>) -> Result<Self, RateLimiterError> {
-        bincode::deserialize(&bytes).map_err(|e| RateLimiterError::MalformedValue(Some(e)))
+    fn from_bytes<C: Codec>(bytes: Vec<u8>) -> Result<Self, RateLimiterError> {
+        C::decode(&bytes).map_err(|e| RateLimiterError::MalformedValue(Some(e)))
     }
-    fn to_bytes(self) -> Result<Vec<u8>, RateLimiterError> {
-        bincode::serialize(&self).map_err(|e| RateLimiterError::MalformedValue(Some(e)))
+    fn to_bytes<C: Codec>(self) -> Result<Vec<u8>, RateLimiterError> {
+        C::encode(&self).map_err(|e| RateLimiterError::MalformedValue(Some(e)))
     }
 }
 
 #[derive(Debug)]
 pub enum RateLimiterError {
-    MalformedValue(Option<bincode::Error>),
+    MalformedValue(Option<CodecError>),
     RateExceeded,
     BackendError(BackendError),
     BackendConflict,
+    UnknownCategory(String),
 }
 
 impl Display for RateLimiterError {
@@ -82,6 +203,7 @@ impl Display for RateLimiterError {
             RateLimiterError::RateExceeded => write!(f, "rate exceeded"),
             RateLimiterError::BackendError(e) => std::fmt::Display::fmt(&e, f),
             RateLimiterError::BackendConflict => write!(f, "backend value conflict"),
+            RateLimiterError::UnknownCategory(category) => write!(f, "no limiter registered for category `{category}`"),
         }
     }
 }
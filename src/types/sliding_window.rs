@@ -0,0 +1,116 @@
+use super::{now_ns, LimiterInstance, LimiterType, RateLimitStatus, RateLimiterError, SerializableInstance};
+use crate::codec::{BincodeCodec, Codec};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub struct SlidingWindow<C: Codec = BincodeCodec> {
+    pub size: u32,
+    pub window: Duration,
+    _codec: PhantomData<C>,
+}
+
+impl<C: Codec> Clone for SlidingWindow<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Codec> Copy for SlidingWindow<C> {}
+
+impl<C: Codec> fmt::Debug for SlidingWindow<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlidingWindow")
+            .field("size", &self.size)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct SlidingWindowInstance {
+    pub previous_count: u32,
+    pub current_count: u32,
+    pub current_window_start: u64,
+}
+
+impl<C: Codec> SlidingWindow<C> {
+    pub fn new(size: u32, window: Duration) -> Self {
+        Self {
+            size,
+            window,
+            _codec: PhantomData,
+        }
+    }
+
+    fn current(&self, instance: Option<SlidingWindowInstance>, now: u64) -> SlidingWindowInstance {
+        let window_ns = self.window.as_nanos().max(1) as u64;
+        let mut instance = instance.unwrap_or(SlidingWindowInstance {
+            previous_count: 0,
+            current_count: 0,
+            current_window_start: now,
+        });
+
+        let elapsed = now.saturating_sub(instance.current_window_start);
+        if elapsed >= 2 * window_ns {
+            instance.previous_count = 0;
+            instance.current_count = 0;
+            instance.current_window_start = now;
+        } else if elapsed >= window_ns {
+            instance.previous_count = instance.current_count;
+            instance.current_count = 0;
+            instance.current_window_start += window_ns;
+        }
+        instance
+    }
+
+    fn weighted_count(&self, instance: &SlidingWindowInstance, now: u64) -> u64 {
+        let window_ns = self.window.as_nanos().max(1) as u64;
+        let elapsed_in_current = now.saturating_sub(instance.current_window_start).min(window_ns);
+        let weight = window_ns.saturating_sub(elapsed_in_current);
+        (instance.previous_count as u128 * weight as u128 / window_ns as u128) as u64 + instance.current_count as u64
+    }
+}
+
+impl SerializableInstance for SlidingWindowInstance {}
+
+impl<C: Codec> LimiterType for SlidingWindow<C> {
+    fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError> {
+        let instance = value.map(SlidingWindowInstance::from_bytes::<C>).transpose()?;
+        let now = now_ns();
+        let mut instance = self.current(instance, now);
+
+        if self.weighted_count(&instance, now) >= self.size as u64 {
+            return Err(RateLimiterError::RateExceeded);
+        }
+        instance.current_count += 1;
+        instance.to_bytes::<C>()
+    }
+
+    fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        Ok(LimiterInstance::SlidingWindowInstance(
+            SlidingWindowInstance::from_bytes::<C>(value)?,
+        ))
+    }
+
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError> {
+        let instance = value.map(SlidingWindowInstance::from_bytes::<C>).transpose()?;
+        let now = now_ns();
+        let instance = self.current(instance, now);
+
+        let weighted = self.weighted_count(&instance, now);
+        let remaining = (self.size as u64).saturating_sub(weighted) as u32;
+        let window_ns = self.window.as_nanos().max(1) as u64;
+        let reset_after = Duration::from_nanos(
+            window_ns.saturating_sub(now.saturating_sub(instance.current_window_start)),
+        );
+
+        Ok(RateLimitStatus {
+            limit: self.size,
+            remaining,
+            reset_after,
+            retry_after: (remaining == 0).then_some(reset_after),
+        })
+    }
+}
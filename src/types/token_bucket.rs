@@ -0,0 +1,122 @@
+use super::{now_ns, LimiterInstance, LimiterType, RateLimitStatus, RateLimiterError, SerializableInstance};
+use crate::codec::{BincodeCodec, Codec};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub struct TokenBucket<C: Codec = BincodeCodec> {
+    pub size: u32,
+    pub complete_refill_time: Duration,
+    pub one_time_burst: u32,
+    _codec: PhantomData<C>,
+}
+
+impl<C: Codec> Clone for TokenBucket<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Codec> Copy for TokenBucket<C> {}
+
+impl<C: Codec> fmt::Debug for TokenBucket<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenBucket")
+            .field("size", &self.size)
+            .field("complete_refill_time", &self.complete_refill_time)
+            .field("one_time_burst", &self.one_time_burst)
+            .finish()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct TokenBucketInstance {
+    pub tokens: u32,
+    pub burst_tokens: u32,
+    pub last_refill: u64,
+}
+
+impl SerializableInstance for TokenBucketInstance {}
+
+impl<C: Codec> TokenBucket<C> {
+    pub fn new(size: u32, complete_refill_time: Duration, one_time_burst: u32) -> Self {
+        Self {
+            size,
+            complete_refill_time,
+            one_time_burst,
+            _codec: PhantomData,
+        }
+    }
+
+    fn replenish(&self, instance: Option<TokenBucketInstance>, now: u64) -> TokenBucketInstance {
+        let mut instance = instance.unwrap_or(TokenBucketInstance {
+            tokens: self.size,
+            burst_tokens: self.one_time_burst,
+            last_refill: now,
+        });
+
+        let refill_ns = self.complete_refill_time.as_nanos().max(1);
+        let elapsed_ns = now.saturating_sub(instance.last_refill);
+        let tokens_to_add = (elapsed_ns as u128 * self.size as u128) / refill_ns;
+        if tokens_to_add > 0 {
+            instance.tokens = (instance.tokens as u128 + tokens_to_add).min(self.size as u128) as u32;
+            instance.last_refill = now;
+        }
+
+        instance
+    }
+
+    /// Replenishes `instance` (or starts a fresh one) and checks out `amount`
+    /// tokens from it, drawing on the one-time burst pool only once the main
+    /// budget is empty. Returns the new instance without persisting anything,
+    /// so callers that need to gate on multiple buckets can check each one
+    /// before committing any of them.
+    pub(crate) fn consume(
+        &self,
+        instance: Option<TokenBucketInstance>,
+        amount: u32,
+    ) -> Result<TokenBucketInstance, RateLimiterError> {
+        let mut instance = self.replenish(instance, now_ns());
+
+        let available = instance.tokens as u64 + instance.burst_tokens as u64;
+        if available < amount as u64 {
+            return Err(RateLimiterError::RateExceeded);
+        }
+
+        let from_tokens = amount.min(instance.tokens);
+        instance.tokens -= from_tokens;
+        instance.burst_tokens -= amount - from_tokens;
+
+        Ok(instance)
+    }
+}
+
+impl<C: Codec> LimiterType for TokenBucket<C> {
+    fn is_ratelimited(&self, value: Option<Vec<u8>>) -> Result<Vec<u8>, RateLimiterError> {
+        let instance = value.map(TokenBucketInstance::from_bytes::<C>).transpose()?;
+        self.consume(instance, 1)?.to_bytes::<C>()
+    }
+
+    fn window_instance(&self, value: Vec<u8>) -> Result<LimiterInstance, RateLimiterError> {
+        Ok(LimiterInstance::TokenBucketInstance(
+            TokenBucketInstance::from_bytes::<C>(value)?,
+        ))
+    }
+
+    fn status(&self, value: Option<Vec<u8>>) -> Result<RateLimitStatus, RateLimiterError> {
+        let instance = value.map(TokenBucketInstance::from_bytes::<C>).transpose()?;
+        let instance = self.replenish(instance, now_ns());
+
+        let remaining = instance.tokens.saturating_add(instance.burst_tokens);
+        let refill_ns = self.complete_refill_time.as_nanos().max(1) as u64;
+        let reset_after = Duration::from_nanos(refill_ns / self.size.max(1) as u64);
+
+        Ok(RateLimitStatus {
+            limit: self.size,
+            remaining,
+            reset_after,
+            retry_after: (instance.tokens == 0 && instance.burst_tokens == 0).then_some(reset_after),
+        })
+    }
+}